@@ -14,6 +14,83 @@ pub const EMPTY: Bitboard = 0;
 /// Bitboard with all bits set to one.
 pub const UNIVERSAL: Bitboard = u64::max_value();
 
+pub const FILE_A: Bitboard = 0x0101010101010101;
+pub const FILE_B: Bitboard = FILE_A << 1;
+pub const FILE_C: Bitboard = FILE_A << 2;
+pub const FILE_D: Bitboard = FILE_A << 3;
+pub const FILE_E: Bitboard = FILE_A << 4;
+pub const FILE_F: Bitboard = FILE_A << 5;
+pub const FILE_G: Bitboard = FILE_A << 6;
+pub const FILE_H: Bitboard = FILE_A << 7;
+
+pub const RANK_1: Bitboard = 0x00000000000000ff;
+pub const RANK_2: Bitboard = RANK_1 << 8;
+pub const RANK_3: Bitboard = RANK_1 << 16;
+pub const RANK_4: Bitboard = RANK_1 << 24;
+pub const RANK_5: Bitboard = RANK_1 << 32;
+pub const RANK_6: Bitboard = RANK_1 << 40;
+pub const RANK_7: Bitboard = RANK_1 << 48;
+pub const RANK_8: Bitboard = RANK_1 << 56;
+
+/// File masks, indexed by file.
+pub const FILES: [Bitboard; 8] = [
+    FILE_A, FILE_B, FILE_C, FILE_D, FILE_E, FILE_F, FILE_G, FILE_H,
+];
+
+/// Rank masks, indexed by rank.
+pub const RANKS: [Bitboard; 8] = [
+    RANK_1, RANK_2, RANK_3, RANK_4, RANK_5, RANK_6, RANK_7, RANK_8,
+];
+
+/// Diagonal (parallel to a1-h8) going through each square, indexed by square.
+pub const DIAGONALS: [Bitboard; 64] = diagonals(false);
+
+/// Anti-diagonal (parallel to h1-a8) going through each square, indexed by square.
+pub const ANTI_DIAGONALS: [Bitboard; 64] = diagonals(true);
+
+pub const LIGHT_SQUARES: Bitboard = 0x55aa55aa55aa55aa;
+pub const DARK_SQUARES: Bitboard = !LIGHT_SQUARES;
+
+/// The d4, e4, d5 and e5 squares.
+pub const CENTRE: Bitboard = (FILE_D | FILE_E) & (RANK_4 | RANK_5);
+
+/// The 16 squares from c3 to f6.
+pub const EXTENDED_CENTRE: Bitboard =
+    (FILE_C | FILE_D | FILE_E | FILE_F) & (RANK_3 | RANK_4 | RANK_5 | RANK_6);
+
+/// Files a to d.
+pub const QUEENSIDE: Bitboard = FILE_A | FILE_B | FILE_C | FILE_D;
+
+/// Files e to h.
+pub const KINGSIDE: Bitboard = FILE_E | FILE_F | FILE_G | FILE_H;
+
+/// Ranks 1 to 4.
+pub const WHITE_HALF: Bitboard = RANK_1 | RANK_2 | RANK_3 | RANK_4;
+
+/// Ranks 5 to 8.
+pub const BLACK_HALF: Bitboard = RANK_5 | RANK_6 | RANK_7 | RANK_8;
+
+const fn diagonals(anti: bool) -> [Bitboard; 64] {
+    let mut masks = [EMPTY; 64];
+    let mut sq = 0;
+    while sq < 64 {
+        let mut other = 0;
+        while other < 64 {
+            let same = if anti {
+                sq % 8 + sq / 8 == other % 8 + other / 8
+            } else {
+                sq % 8 + other / 8 == other % 8 + sq / 8
+            };
+            if same {
+                masks[sq] |= 1 << other;
+            }
+            other += 1;
+        }
+        sq += 1;
+    }
+    masks
+}
+
 /// Checks if the bitboard is empty.
 pub fn is_empty(bitboard: Bitboard) -> bool {
     bitboard == EMPTY
@@ -116,7 +193,7 @@ pub fn mirror(mut x: Bitboard) -> Bitboard {
     // . 1 . 1 . 1 . 1
     // . 1 . 1 . 1 . 1
     // . 1 . 1 . 1 . 1
-    const K1: u64 = 0x5555555555555555;
+    const K1: u64 = FILE_A | FILE_C | FILE_E | FILE_G;
 
     // . . 1 1 . . 1 1
     // . . 1 1 . . 1 1
@@ -126,7 +203,7 @@ pub fn mirror(mut x: Bitboard) -> Bitboard {
     // . . 1 1 . . 1 1
     // . . 1 1 . . 1 1
     // . . 1 1 . . 1 1
-    const K2: u64 = 0x3333333333333333;
+    const K2: u64 = FILE_A | FILE_B | FILE_E | FILE_F;
 
     // . . . . 1 1 1 1
     // . . . . 1 1 1 1
@@ -136,7 +213,7 @@ pub fn mirror(mut x: Bitboard) -> Bitboard {
     // . . . . 1 1 1 1
     // . . . . 1 1 1 1
     // . . . . 1 1 1 1
-    const K4: u64 = 0x0f0f0f0f0f0f0f0f;
+    const K4: u64 = QUEENSIDE;
 
     x = ((x >> 1) & K1) | ((x & K1) << 1);
     x = ((x >> 2) & K2) | ((x & K2) << 2);
@@ -145,6 +222,27 @@ pub fn mirror(mut x: Bitboard) -> Bitboard {
     return x;
 }
 
+/// Fills every square north of the set squares, the set squares included.
+pub fn north_fill(mut bitboard: Bitboard) -> Bitboard {
+    bitboard |= bitboard << 8;
+    bitboard |= bitboard << 16;
+    bitboard |= bitboard << 32;
+    bitboard
+}
+
+/// Fills every square south of the set squares, the set squares included.
+pub fn south_fill(mut bitboard: Bitboard) -> Bitboard {
+    bitboard |= bitboard >> 8;
+    bitboard |= bitboard >> 16;
+    bitboard |= bitboard >> 32;
+    bitboard
+}
+
+/// Fills the whole file of every set square.
+pub fn file_fill(bitboard: Bitboard) -> Bitboard {
+    north_fill(bitboard) | south_fill(bitboard)
+}
+
 impl std::fmt::Debug for BitboardWrapper {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let self_m = mirror(self.0);
@@ -162,6 +260,10 @@ mod tests {
     mod bitboard {
         pub use super::super::*;
     }
+    use crate::bitboard::BitboardWrapper;
+    use crate::colour;
+    use crate::file::FileWrapper;
+    use crate::rank::RankWrapper;
     use crate::square;
 
     #[test]
@@ -355,4 +457,203 @@ mod tests {
 
         assert_eq!(bitboard::mirror(FROM), TO);
     }
+
+    #[test]
+    fn north_fill() {
+        use bitboard::Bitboard;
+
+        // . . . . . . . .      . 1 . . . . 1 .
+        // . . . . . . . .      . 1 . . . . 1 .
+        // . 1 . . . . . .      . 1 . . . . 1 .
+        // . . . . . . . .      . . . . . . 1 .
+        // . . . . . . . . -->  . . . . . . 1 .
+        // . . . . . . 1 .      . . . . . . 1 .
+        // . . . . . . . .      . . . . . . . .
+        // . . . . . . . .      . . . . . . . .
+        const FROM: Bitboard = 0x0000400000020000;
+        const TO: Bitboard = 0x4242420202020000;
+
+        assert_eq!(
+            BitboardWrapper(bitboard::north_fill(FROM)),
+            BitboardWrapper(TO)
+        );
+    }
+
+    #[test]
+    fn south_fill() {
+        use bitboard::Bitboard;
+
+        // . . . . . . . .      . . . . . . . .
+        // . . . . . . . .      . . . . . . . .
+        // . 1 . . . . . .      . 1 . . . . . .
+        // . . . . . . . .      . 1 . . . . . .
+        // . . . . . . . . -->  . 1 . . . . . .
+        // . . . . . . 1 .      . 1 . . . . 1 .
+        // . . . . . . . .      . 1 . . . . 1 .
+        // . . . . . . . .      . 1 . . . . 1 .
+        const FROM: Bitboard = 0x0000400000020000;
+        const TO: Bitboard = 0x0000404040424242;
+
+        assert_eq!(
+            BitboardWrapper(bitboard::south_fill(FROM)),
+            BitboardWrapper(TO)
+        );
+    }
+
+    #[test]
+    fn file_fill() {
+        use bitboard::Bitboard;
+
+        // . . . . . . . .      . 1 . . . . 1 .
+        // . . . . . . . .      . 1 . . . . 1 .
+        // . 1 . . . . . .      . 1 . . . . 1 .
+        // . . . . . . . .      . 1 . . . . 1 .
+        // . . . . . . . . -->  . 1 . . . . 1 .
+        // . . . . . . 1 .      . 1 . . . . 1 .
+        // . . . . . . . .      . 1 . . . . 1 .
+        // . . . . . . . .      . 1 . . . . 1 .
+        const FROM: Bitboard = 0x0000400000020000;
+        const TO: Bitboard = 0x4242424242424242;
+
+        assert_eq!(
+            BitboardWrapper(bitboard::file_fill(FROM)),
+            BitboardWrapper(TO)
+        );
+    }
+
+    #[test]
+    fn files_and_ranks() {
+        for square in 0..64 {
+            for file in 0..8 {
+                assert_eq!(
+                    file == square::file(square),
+                    bitboard::FILES[file as usize] & (1 << square) != 0,
+                    "Square {:?} and file {:?} failed",
+                    square::SquareWrapper(square),
+                    FileWrapper(file)
+                );
+            }
+            for rank in 0..8 {
+                assert_eq!(
+                    rank == square::rank(square),
+                    bitboard::RANKS[rank as usize] & (1 << square) != 0,
+                    "Square {:?} and rank {:?} failed",
+                    square::SquareWrapper(square),
+                    RankWrapper(rank)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn light_and_dark_squares() {
+        assert_eq!(
+            bitboard::UNIVERSAL,
+            bitboard::LIGHT_SQUARES | bitboard::DARK_SQUARES
+        );
+        for square in 0..64 {
+            assert_eq!(
+                square::colour(square) == colour::WHITE,
+                bitboard::LIGHT_SQUARES & (1 << square) != 0,
+                "Square {:?} failed",
+                square::SquareWrapper(square)
+            );
+        }
+    }
+
+    #[test]
+    #[rustfmt::skip]
+    fn diagonals() {
+        for (i, &(square, squares)) in [
+            (square::C1, &vec![square::C1, square::D2, square::E3, square::F4, square::G5, square::H6]),
+            (square::F4, &vec![square::C1, square::D2, square::E3, square::F4, square::G5, square::H6]),
+            (square::A8, &vec![square::A8]),
+            (square::H1, &vec![square::H1]),
+            (square::E4, &vec![
+                square::B1, square::C2, square::D3, square::E4,
+                square::F5, square::G6, square::H7,
+            ]),
+        ]
+        .iter()
+        .enumerate()
+        {
+            let mut diagonal = bitboard::EMPTY;
+            for &square in squares.iter() {
+                diagonal |= 1 << square;
+            }
+            let diagonal = diagonal;
+
+            assert_eq!(
+                BitboardWrapper(diagonal),
+                BitboardWrapper(bitboard::DIAGONALS[square as usize]),
+                "Test case #{} failed",
+                i
+            );
+        }
+    }
+
+    #[test]
+    #[rustfmt::skip]
+    fn anti_diagonals() {
+        for (i, &(square, squares)) in [
+            (square::C1, &vec![square::C1, square::B2, square::A3]),
+            (square::A3, &vec![square::C1, square::B2, square::A3]),
+            (square::A1, &vec![square::A1]),
+            (square::H8, &vec![square::H8]),
+            (square::E4, &vec![
+                square::H1, square::G2, square::F3, square::E4,
+                square::D5, square::C6, square::B7, square::A8,
+            ]),
+        ]
+        .iter()
+        .enumerate()
+        {
+            let mut diagonal = bitboard::EMPTY;
+            for &square in squares.iter() {
+                diagonal |= 1 << square;
+            }
+            let diagonal = diagonal;
+
+            assert_eq!(
+                BitboardWrapper(diagonal),
+                BitboardWrapper(bitboard::ANTI_DIAGONALS[square as usize]),
+                "Test case #{} failed",
+                i
+            );
+        }
+    }
+
+    #[test]
+    fn diagonals_cross_on_their_square() {
+        for square in 0..64 {
+            assert_eq!(
+                BitboardWrapper(1 << square),
+                BitboardWrapper(bitboard::DIAGONALS[square] & bitboard::ANTI_DIAGONALS[square]),
+                "Square {:?} failed",
+                square::SquareWrapper(square as u8)
+            );
+        }
+    }
+
+    #[test]
+    fn regions() {
+        assert_eq!(4, bitboard::cardinality(bitboard::CENTRE));
+        assert_eq!(16, bitboard::cardinality(bitboard::EXTENDED_CENTRE));
+        assert_eq!(
+            bitboard::CENTRE,
+            bitboard::CENTRE & bitboard::EXTENDED_CENTRE
+        );
+
+        assert_eq!(bitboard::EMPTY, bitboard::QUEENSIDE & bitboard::KINGSIDE);
+        assert_eq!(
+            bitboard::UNIVERSAL,
+            bitboard::QUEENSIDE | bitboard::KINGSIDE
+        );
+
+        assert_eq!(bitboard::EMPTY, bitboard::WHITE_HALF & bitboard::BLACK_HALF);
+        assert_eq!(
+            bitboard::UNIVERSAL,
+            bitboard::WHITE_HALF | bitboard::BLACK_HALF
+        );
+    }
 }
@@ -0,0 +1,22 @@
+pub type Colour = u8;
+
+/// Wraps the given colour to be represented by a debug formatter.
+#[derive(Eq, PartialEq)]
+pub struct ColourWrapper(pub Colour);
+
+pub const WHITE: Colour = 0;
+pub const BLACK: Colour = 1;
+
+impl std::fmt::Debug for ColourWrapper {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self.0 {
+                WHITE => "White",
+                BLACK => "Black",
+                _ => unreachable!(),
+            }
+        )
+    }
+}
@@ -0,0 +1,57 @@
+pub type Direction = u8;
+
+/// Wraps the given direction to be represented by a debug formatter.
+#[derive(Eq, PartialEq)]
+pub struct DirectionWrapper(pub Direction);
+
+pub const NORTH: Direction = 0;
+pub const NORTH_EAST: Direction = 1;
+pub const EAST: Direction = 2;
+pub const SOUTH_EAST: Direction = 3;
+pub const SOUTH: Direction = 4;
+pub const SOUTH_WEST: Direction = 5;
+pub const WEST: Direction = 6;
+pub const NORTH_WEST: Direction = 7;
+
+/// Returns the file offset of a single step in the direction.
+pub const fn file_step(direction: Direction) -> i8 {
+    match direction {
+        NORTH_EAST | EAST | SOUTH_EAST => 1,
+        SOUTH_WEST | WEST | NORTH_WEST => -1,
+        _ => 0,
+    }
+}
+
+/// Returns the rank offset of a single step in the direction.
+pub const fn rank_step(direction: Direction) -> i8 {
+    match direction {
+        NORTH_WEST | NORTH | NORTH_EAST => 1,
+        SOUTH_EAST | SOUTH | SOUTH_WEST => -1,
+        _ => 0,
+    }
+}
+
+/// Returns the direction pointing the other way.
+pub const fn opposite(direction: Direction) -> Direction {
+    (direction + 4) % 8
+}
+
+impl std::fmt::Debug for DirectionWrapper {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self.0 {
+                NORTH => "N",
+                NORTH_EAST => "NE",
+                EAST => "E",
+                SOUTH_EAST => "SE",
+                SOUTH => "S",
+                SOUTH_WEST => "SW",
+                WEST => "W",
+                NORTH_WEST => "NW",
+                _ => unreachable!(),
+            }
+        )
+    }
+}
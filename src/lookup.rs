@@ -1,5 +1,7 @@
 use crate::bitboard;
 use crate::bitboard::Bitboard;
+use crate::direction;
+use crate::direction::Direction;
 use crate::square;
 use crate::square::Square;
 
@@ -18,13 +20,16 @@ macro_rules! chessboard {
     };
 }
 
+// The king, knight and pawn attack tables are laid out file-mirrored, starting
+// from H1 (see the label on each entry), hence the mirrored square in lookups.
+
 pub struct KingAttackTable {
     lookup: [Bitboard; 64],
 }
 
 impl KingAttackTable {
     pub fn lookup(&self, square: Square) -> Bitboard {
-        self.lookup[square as usize]
+        self.lookup[square::mirror(square) as usize]
     }
 }
 
@@ -34,7 +39,7 @@ pub struct KnightAttackTable {
 
 impl KnightAttackTable {
     pub fn lookup(&self, square: Square) -> Bitboard {
-        self.lookup[square as usize]
+        self.lookup[square::mirror(square) as usize]
     }
 }
 
@@ -45,7 +50,7 @@ pub struct WhitePawnAttackTable {
 impl WhitePawnAttackTable {
     pub fn lookup(&self, square: Square) -> Bitboard {
         debug_assert!(square as usize >= 8);
-        self.lookup[square as usize - 8]
+        self.lookup[square::mirror(square) as usize - 8]
     }
 }
 
@@ -56,7 +61,7 @@ pub struct BlackPawnAttackTable {
 impl BlackPawnAttackTable {
     pub fn lookup(&self, square: Square) -> Bitboard {
         debug_assert!((square as usize) < 56);
-        self.lookup[square as usize]
+        self.lookup[square::mirror(square) as usize]
     }
 }
 
@@ -137,6 +142,239 @@ impl BlackPawnPushTable {
     }
 }
 
+struct RayTable {
+    lookup: [[Bitboard; 64]; 8],
+}
+
+impl RayTable {
+    const fn new() -> RayTable {
+        let mut table = RayTable {
+            lookup: [[bitboard::EMPTY; 64]; 8],
+        };
+        let mut dir = 0;
+        while dir < 8 {
+            let mut sq = 0;
+            while sq < 64 {
+                let mut file = (sq % 8) as i8;
+                let mut rank = (sq / 8) as i8;
+                loop {
+                    file += direction::file_step(dir as Direction);
+                    rank += direction::rank_step(dir as Direction);
+                    if file < 0 || file > 7 || rank < 0 || rank > 7 {
+                        break;
+                    }
+                    table.lookup[dir][sq] |= 1 << (rank * 8 + file);
+                }
+                sq += 1;
+            }
+            dir += 1;
+        }
+        table
+    }
+
+    fn lookup(&self, square: Square, direction: Direction) -> Bitboard {
+        self.lookup[direction as usize][square as usize]
+    }
+}
+
+pub struct BetweenTable {
+    lookup: [[Bitboard; 64]; 64],
+}
+
+impl BetweenTable {
+    const fn new() -> BetweenTable {
+        let rays = RayTable::new();
+        let mut table = BetweenTable {
+            lookup: [[bitboard::EMPTY; 64]; 64],
+        };
+        let mut sq = 0;
+        while sq < 64 {
+            let mut dir = 0;
+            while dir < 8 {
+                let ray = rays.lookup[dir][sq];
+                let back = direction::opposite(dir as Direction) as usize;
+                let mut rest = ray;
+                while rest != 0 {
+                    let to = rest.trailing_zeros() as usize;
+                    rest &= rest - 1;
+                    table.lookup[sq][to] = ray & rays.lookup[back][to];
+                }
+                dir += 1;
+            }
+            sq += 1;
+        }
+        table
+    }
+
+    /// Returns the squares strictly between the two given squares.
+    ///
+    /// The result is empty if the squares do not share a rank, file or
+    /// diagonal, or if they are equal or adjacent.
+    pub fn lookup(&self, a: Square, b: Square) -> Bitboard {
+        self.lookup[a as usize][b as usize]
+    }
+}
+
+pub struct LineTable {
+    lookup: [[Bitboard; 64]; 64],
+}
+
+impl LineTable {
+    const fn new() -> LineTable {
+        let rays = RayTable::new();
+        let mut table = LineTable {
+            lookup: [[bitboard::EMPTY; 64]; 64],
+        };
+        let mut sq = 0;
+        while sq < 64 {
+            let mut dir = 0;
+            while dir < 8 {
+                let back = direction::opposite(dir as Direction) as usize;
+                let line = rays.lookup[dir][sq] | rays.lookup[back][sq] | (1 << sq);
+                let mut rest = rays.lookup[dir][sq];
+                while rest != 0 {
+                    let to = rest.trailing_zeros() as usize;
+                    rest &= rest - 1;
+                    table.lookup[sq][to] = line;
+                }
+                dir += 1;
+            }
+            sq += 1;
+        }
+        table
+    }
+
+    /// Returns the whole line, from edge to edge, passing through the two
+    /// given squares (both included).
+    ///
+    /// The result is empty if the squares do not share a rank, file or
+    /// diagonal, or if they are equal.
+    pub fn lookup(&self, a: Square, b: Square) -> Bitboard {
+        self.lookup[a as usize][b as usize]
+    }
+}
+
+pub struct DistanceTable {
+    lookup: [[u8; 64]; 64],
+}
+
+impl DistanceTable {
+    const fn new() -> DistanceTable {
+        let mut table = DistanceTable {
+            lookup: [[0; 64]; 64],
+        };
+        let mut a: usize = 0;
+        while a < 64 {
+            let mut b: usize = 0;
+            while b < 64 {
+                let files = (a % 8).abs_diff(b % 8) as u8;
+                let ranks = (a / 8).abs_diff(b / 8) as u8;
+                table.lookup[a][b] = if files > ranks { files } else { ranks };
+                b += 1;
+            }
+            a += 1;
+        }
+        table
+    }
+
+    /// Returns the Chebyshev distance between two squares, i.e. the number
+    /// of king moves it takes to go from one to the other.
+    pub fn lookup(&self, a: Square, b: Square) -> u8 {
+        self.lookup[a as usize][b as usize]
+    }
+}
+
+pub struct ManhattanDistanceTable {
+    lookup: [[u8; 64]; 64],
+}
+
+impl ManhattanDistanceTable {
+    const fn new() -> ManhattanDistanceTable {
+        let mut table = ManhattanDistanceTable {
+            lookup: [[0; 64]; 64],
+        };
+        let mut a: usize = 0;
+        while a < 64 {
+            let mut b: usize = 0;
+            while b < 64 {
+                let files = (a % 8).abs_diff(b % 8) as u8;
+                let ranks = (a / 8).abs_diff(b / 8) as u8;
+                table.lookup[a][b] = files + ranks;
+                b += 1;
+            }
+            a += 1;
+        }
+        table
+    }
+
+    /// Returns the Manhattan distance between two squares, i.e. the sum of
+    /// their file and rank distances.
+    pub fn lookup(&self, a: Square, b: Square) -> u8 {
+        self.lookup[a as usize][b as usize]
+    }
+}
+
+pub struct CentreDistanceTable {
+    lookup: [u8; 64],
+}
+
+impl CentreDistanceTable {
+    const fn new() -> CentreDistanceTable {
+        let mut table = CentreDistanceTable { lookup: [0; 64] };
+        let mut sq = 0;
+        while sq < 64 {
+            // Distance of the file (resp. rank) to the closest of the two central ones
+            let file = if sq % 8 < 4 { 3 - sq % 8 } else { sq % 8 - 4 };
+            let rank = if sq / 8 < 4 { 3 - sq / 8 } else { sq / 8 - 4 };
+            table.lookup[sq] = (if file > rank { file } else { rank }) as u8;
+            sq += 1;
+        }
+        table
+    }
+
+    /// Returns the Chebyshev distance from the square to the closest of
+    /// the four central squares (d4, e4, d5 and e5).
+    pub fn lookup(&self, square: Square) -> u8 {
+        self.lookup[square as usize]
+    }
+}
+
+pub struct DirectionTable {
+    lookup: [[Option<Direction>; 64]; 64],
+}
+
+impl DirectionTable {
+    const fn new() -> DirectionTable {
+        let rays = RayTable::new();
+        let mut table = DirectionTable {
+            lookup: [[None; 64]; 64],
+        };
+        let mut sq = 0;
+        while sq < 64 {
+            let mut dir = 0;
+            while dir < 8 {
+                let mut rest = rays.lookup[dir][sq];
+                while rest != 0 {
+                    let to = rest.trailing_zeros() as usize;
+                    rest &= rest - 1;
+                    table.lookup[sq][to] = Some(dir as Direction);
+                }
+                dir += 1;
+            }
+            sq += 1;
+        }
+        table
+    }
+
+    /// Returns the direction leading from the first square to the second.
+    ///
+    /// Returns `None` if the squares do not share a rank, file or diagonal,
+    /// or if they are equal.
+    pub fn lookup(&self, from: Square, to: Square) -> Option<Direction> {
+        self.lookup[from as usize][to as usize]
+    }
+}
+
 pub const KING_ATTACK_TABLE: KingAttackTable = KingAttackTable {
     lookup: [
         // [0] <-> H1
@@ -930,7 +1168,7 @@ pub const KNIGHT_ATTACK_TABLE: KnightAttackTable = KnightAttackTable {
             0b_00000000
             0b_00000000
             0b_00000000
-            0b_00000100
+            0b_00000101
             0b_00001000
             0b_00000000
             0b_00001000),
@@ -1408,7 +1646,7 @@ pub const KNIGHT_ATTACK_TABLE: KnightAttackTable = KnightAttackTable {
         chessboard!(
             0b_00000000
             0b_00001000
-            0b_00000100
+            0b_00000101
             0b_00000000
             0b_00000000
             0b_00000000
@@ -2562,12 +2800,36 @@ lazy_static! {
     pub static ref BLACK_PAWN_PUSH_TABLE: BlackPawnPushTable = BlackPawnPushTable::new();
 }
 
+static RAY_TABLE: RayTable = RayTable::new();
+pub static BETWEEN_TABLE: BetweenTable = BetweenTable::new();
+pub static LINE_TABLE: LineTable = LineTable::new();
+pub static DISTANCE_TABLE: DistanceTable = DistanceTable::new();
+pub static MANHATTAN_DISTANCE_TABLE: ManhattanDistanceTable = ManhattanDistanceTable::new();
+pub static CENTRE_DISTANCE_TABLE: CentreDistanceTable = CentreDistanceTable::new();
+pub static DIRECTION_TABLE: DirectionTable = DirectionTable::new();
+
+/// Returns the squares reached by sliding from the given square in the given
+/// direction up to the board edge, excluding the origin square.
+pub fn ray(from: Square, direction: Direction) -> Bitboard {
+    RAY_TABLE.lookup(from, direction)
+}
+
+/// Checks if the three squares lie on a single rank, file or diagonal.
+///
+/// Always false if `a` and `b` are equal, since they do not define a line.
+pub fn aligned(a: Square, b: Square, c: Square) -> bool {
+    LINE_TABLE.lookup(a, b) & (1 << c) != 0
+}
+
 #[cfg(test)]
 mod test {
     mod lookup {
         pub use super::super::*;
     }
+    use crate::bitboard;
     use crate::bitboard::BitboardWrapper;
+    use crate::direction;
+    use crate::direction::DirectionWrapper;
     use crate::square;
 
     #[test]
@@ -3105,4 +3367,412 @@ mod test {
             );
         }
     }
+
+    #[test]
+    #[rustfmt::skip]
+    fn king_attack_table() {
+        for (i, &(from, squares)) in [
+            (square::A1, &vec![square::B1, square::A2, square::B2]),
+            (square::H1, &vec![square::G1, square::G2, square::H2]),
+            (square::H8, &vec![square::G8, square::G7, square::H7]),
+            (square::B1, &vec![square::A1, square::C1, square::A2, square::B2, square::C2]),
+            (square::E4, &vec![
+                square::D3, square::E3, square::F3, square::D4,
+                square::F4, square::D5, square::E5, square::F5,
+            ]),
+        ]
+        .iter()
+        .enumerate()
+        {
+            let mut attacks = bitboard::EMPTY;
+            for &square in squares.iter() {
+                attacks |= 1 << square;
+            }
+            let attacks = attacks;
+
+            assert_eq!(
+                BitboardWrapper(attacks),
+                BitboardWrapper(lookup::KING_ATTACK_TABLE.lookup(from)),
+                "Test case #{} failed",
+                i
+            );
+        }
+    }
+
+    #[test]
+    #[rustfmt::skip]
+    fn knight_attack_table() {
+        for (i, &(from, squares)) in [
+            (square::A1, &vec![square::B3, square::C2]),
+            (square::G1, &vec![square::E2, square::F3, square::H3]),
+            (square::B2, &vec![square::A4, square::C4, square::D3, square::D1]),
+            (square::B8, &vec![square::A6, square::C6, square::D7]),
+            (square::H8, &vec![square::G6, square::F7]),
+            (square::E4, &vec![
+                square::D2, square::F2, square::C3, square::G3,
+                square::C5, square::G5, square::D6, square::F6,
+            ]),
+        ]
+        .iter()
+        .enumerate()
+        {
+            let mut attacks = bitboard::EMPTY;
+            for &square in squares.iter() {
+                attacks |= 1 << square;
+            }
+            let attacks = attacks;
+
+            assert_eq!(
+                BitboardWrapper(attacks),
+                BitboardWrapper(lookup::KNIGHT_ATTACK_TABLE.lookup(from)),
+                "Test case #{} failed",
+                i
+            );
+        }
+    }
+
+    #[test]
+    #[rustfmt::skip]
+    fn ray() {
+        for (i, &(from, direction, squares)) in [
+            (square::D4, direction::NORTH, &vec![square::D5, square::D6, square::D7, square::D8]),
+            (square::D4, direction::EAST, &vec![square::E4, square::F4, square::G4, square::H4]),
+            (square::E4, direction::SOUTH_EAST, &vec![square::F3, square::G2, square::H1]),
+            (square::C3, direction::SOUTH_WEST, &vec![square::B2, square::A1]),
+            (square::H1, direction::NORTH_WEST, &vec![
+                square::G2, square::F3, square::E4, square::D5, square::C6, square::B7, square::A8,
+            ]),
+            (square::B7, direction::NORTH_EAST, &vec![square::C8]),
+            (square::A1, direction::WEST, &vec![]),
+            (square::A1, direction::SOUTH, &vec![]),
+            (square::H8, direction::NORTH_EAST, &vec![]),
+        ]
+        .iter()
+        .enumerate()
+        {
+            let mut ray = bitboard::EMPTY;
+            for &square in squares.iter() {
+                ray |= 1 << square;
+            }
+            let ray = ray;
+
+            assert_eq!(
+                BitboardWrapper(ray),
+                BitboardWrapper(lookup::ray(from, direction)),
+                "Test case #{} failed",
+                i
+            );
+        }
+    }
+
+    #[test]
+    #[rustfmt::skip]
+    fn between_table() {
+        for (i, &(a, b, squares)) in [
+            (square::A1, square::H8, &vec![
+                square::B2, square::C3, square::D4, square::E5, square::F6, square::G7,
+            ]),
+            (square::A1, square::A8, &vec![
+                square::A2, square::A3, square::A4, square::A5, square::A6, square::A7,
+            ]),
+            (square::H3, square::C3, &vec![square::G3, square::F3, square::E3, square::D3]),
+            (square::D4, square::G1, &vec![square::E3, square::F2]),
+            (square::B6, square::D8, &vec![square::C7]),
+            // Adjacent squares have nothing in between.
+            (square::E4, square::E5, &vec![]),
+            (square::E4, square::F5, &vec![]),
+            // Squares that are not aligned have nothing in between.
+            (square::A1, square::B3, &vec![]),
+            (square::A1, square::H7, &vec![]),
+            (square::C2, square::F4, &vec![]),
+            // A square has nothing in between with itself.
+            (square::E4, square::E4, &vec![]),
+        ]
+        .iter()
+        .enumerate()
+        {
+            let mut between = bitboard::EMPTY;
+            for &square in squares.iter() {
+                between |= 1 << square;
+            }
+            let between = between;
+
+            assert_eq!(
+                BitboardWrapper(between),
+                BitboardWrapper(lookup::BETWEEN_TABLE.lookup(a, b)),
+                "Test case #{} failed",
+                i
+            );
+            assert_eq!(
+                BitboardWrapper(between),
+                BitboardWrapper(lookup::BETWEEN_TABLE.lookup(b, a)),
+                "Test case #{} (reversed) failed",
+                i
+            );
+        }
+    }
+
+    #[test]
+    #[rustfmt::skip]
+    fn line_table() {
+        for (i, &(a, b, squares)) in [
+            (square::C3, square::E5, &vec![
+                square::A1, square::B2, square::C3, square::D4,
+                square::E5, square::F6, square::G7, square::H8,
+            ]),
+            (square::B3, square::B6, &vec![
+                square::B1, square::B2, square::B3, square::B4,
+                square::B5, square::B6, square::B7, square::B8,
+            ]),
+            (square::D5, square::A5, &vec![
+                square::A5, square::B5, square::C5, square::D5,
+                square::E5, square::F5, square::G5, square::H5,
+            ]),
+            (square::F1, square::G2, &vec![square::F1, square::G2, square::H3]),
+            (square::A8, square::B7, &vec![
+                square::A8, square::B7, square::C6, square::D5,
+                square::E4, square::F3, square::G2, square::H1,
+            ]),
+            // Squares that are not aligned do not define a line.
+            (square::A1, square::B3, &vec![]),
+            (square::G1, square::A8, &vec![]),
+            // A single square does not define a line either.
+            (square::E4, square::E4, &vec![]),
+        ]
+        .iter()
+        .enumerate()
+        {
+            let mut line = bitboard::EMPTY;
+            for &square in squares.iter() {
+                line |= 1 << square;
+            }
+            let line = line;
+
+            assert_eq!(
+                BitboardWrapper(line),
+                BitboardWrapper(lookup::LINE_TABLE.lookup(a, b)),
+                "Test case #{} failed",
+                i
+            );
+            assert_eq!(
+                BitboardWrapper(line),
+                BitboardWrapper(lookup::LINE_TABLE.lookup(b, a)),
+                "Test case #{} (reversed) failed",
+                i
+            );
+        }
+    }
+
+    #[test]
+    fn between_is_part_of_line() {
+        for a in 0..64 {
+            for b in 0..64 {
+                let between = lookup::BETWEEN_TABLE.lookup(a, b);
+                let line = lookup::LINE_TABLE.lookup(a, b);
+                assert_eq!(
+                    between,
+                    between & line,
+                    "Squares {:?} and {:?} failed",
+                    square::SquareWrapper(a),
+                    square::SquareWrapper(b)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn aligned() {
+        for (i, &(a, b, c, aligned)) in [
+            (square::A1, square::C3, square::H8, true),
+            (square::C3, square::H8, square::A1, true),
+            (square::E1, square::E8, square::E4, true),
+            (square::B2, square::G2, square::A2, true),
+            (square::H1, square::A8, square::D4, false),
+            (square::H1, square::A8, square::E4, true),
+            // Knight-like steps are not lines.
+            (square::A1, square::B3, square::C5, false),
+            (square::A1, square::C3, square::D5, false),
+            // Equal squares do not define a line, whatever the third square is.
+            (square::E4, square::E4, square::E5, false),
+            (square::E4, square::E4, square::E4, false),
+        ]
+        .iter()
+        .enumerate()
+        {
+            assert_eq!(aligned, lookup::aligned(a, b, c), "Test case #{} failed", i);
+        }
+    }
+
+    #[test]
+    fn distance_table() {
+        for (i, &(a, b, distance)) in [
+            (square::A1, square::A1, 0),
+            (square::E4, square::E5, 1),
+            (square::E4, square::F5, 1),
+            (square::A1, square::H8, 7),
+            (square::A1, square::H1, 7),
+            (square::A8, square::H1, 7),
+            (square::B1, square::C3, 2),
+            (square::G7, square::D3, 4),
+            (square::C2, square::F4, 3),
+        ]
+        .iter()
+        .enumerate()
+        {
+            assert_eq!(
+                distance,
+                lookup::DISTANCE_TABLE.lookup(a, b),
+                "Test case #{} failed",
+                i
+            );
+            assert_eq!(
+                distance,
+                lookup::DISTANCE_TABLE.lookup(b, a),
+                "Test case #{} (reversed) failed",
+                i
+            );
+        }
+    }
+
+    #[test]
+    fn manhattan_distance_table() {
+        for (i, &(a, b, distance)) in [
+            (square::A1, square::A1, 0),
+            (square::E4, square::E5, 1),
+            (square::E4, square::F5, 2),
+            (square::A1, square::H8, 14),
+            (square::A1, square::H1, 7),
+            (square::A8, square::H1, 14),
+            (square::B1, square::C3, 3),
+            (square::G7, square::D3, 7),
+            (square::C2, square::F4, 5),
+        ]
+        .iter()
+        .enumerate()
+        {
+            assert_eq!(
+                distance,
+                lookup::MANHATTAN_DISTANCE_TABLE.lookup(a, b),
+                "Test case #{} failed",
+                i
+            );
+            assert_eq!(
+                distance,
+                lookup::MANHATTAN_DISTANCE_TABLE.lookup(b, a),
+                "Test case #{} (reversed) failed",
+                i
+            );
+        }
+    }
+
+    #[test]
+    fn centre_distance_table() {
+        for (i, &(square, distance)) in [
+            (square::A1, 3),
+            (square::A2, 3),
+            (square::A3, 3),
+            (square::A4, 3),
+            (square::A5, 3),
+            (square::A6, 3),
+            (square::A7, 3),
+            (square::A8, 3),
+            (square::B1, 3),
+            (square::B2, 2),
+            (square::B3, 2),
+            (square::B4, 2),
+            (square::B5, 2),
+            (square::B6, 2),
+            (square::B7, 2),
+            (square::B8, 3),
+            (square::C1, 3),
+            (square::C2, 2),
+            (square::C3, 1),
+            (square::C4, 1),
+            (square::C5, 1),
+            (square::C6, 1),
+            (square::C7, 2),
+            (square::C8, 3),
+            (square::D1, 3),
+            (square::D2, 2),
+            (square::D3, 1),
+            (square::D4, 0),
+            (square::D5, 0),
+            (square::D6, 1),
+            (square::D7, 2),
+            (square::D8, 3),
+            (square::E1, 3),
+            (square::E2, 2),
+            (square::E3, 1),
+            (square::E4, 0),
+            (square::E5, 0),
+            (square::E6, 1),
+            (square::E7, 2),
+            (square::E8, 3),
+            (square::F1, 3),
+            (square::F2, 2),
+            (square::F3, 1),
+            (square::F4, 1),
+            (square::F5, 1),
+            (square::F6, 1),
+            (square::F7, 2),
+            (square::F8, 3),
+            (square::G1, 3),
+            (square::G2, 2),
+            (square::G3, 2),
+            (square::G4, 2),
+            (square::G5, 2),
+            (square::G6, 2),
+            (square::G7, 2),
+            (square::G8, 3),
+            (square::H1, 3),
+            (square::H2, 3),
+            (square::H3, 3),
+            (square::H4, 3),
+            (square::H5, 3),
+            (square::H6, 3),
+            (square::H7, 3),
+            (square::H8, 3),
+        ]
+        .iter()
+        .enumerate()
+        {
+            assert_eq!(
+                distance,
+                lookup::CENTRE_DISTANCE_TABLE.lookup(square),
+                "Test case #{} failed",
+                i
+            );
+        }
+    }
+
+    #[test]
+    fn direction_table() {
+        for (i, &(from, to, direction)) in [
+            (square::E4, square::E8, Some(direction::NORTH)),
+            (square::E4, square::H7, Some(direction::NORTH_EAST)),
+            (square::E4, square::F4, Some(direction::EAST)),
+            (square::E4, square::H1, Some(direction::SOUTH_EAST)),
+            (square::E4, square::E1, Some(direction::SOUTH)),
+            (square::E4, square::B1, Some(direction::SOUTH_WEST)),
+            (square::E4, square::A4, Some(direction::WEST)),
+            (square::E4, square::A8, Some(direction::NORTH_WEST)),
+            (square::H8, square::A1, Some(direction::SOUTH_WEST)),
+            // Squares that are not aligned have no direction between them.
+            (square::E4, square::F6, None),
+            (square::A1, square::H7, None),
+            (square::E4, square::E4, None),
+        ]
+        .iter()
+        .enumerate()
+        {
+            assert_eq!(
+                direction.map(DirectionWrapper),
+                lookup::DIRECTION_TABLE
+                    .lookup(from, to)
+                    .map(DirectionWrapper),
+                "Test case #{} failed",
+                i
+            );
+        }
+    }
 }
@@ -1,8 +1,12 @@
 mod bitboard;
+mod colour;
+mod direction;
 mod file;
 mod lookup;
+mod pawn;
 mod rank;
 mod square;
+mod timeman;
 
 fn main() {
     println!("Hello, world!");
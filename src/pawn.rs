@@ -0,0 +1,744 @@
+use crate::bitboard;
+use crate::bitboard::Bitboard;
+
+/// Returns the squares white pawns can reach by pushing one square forward.
+pub fn white_single_pushes(pawns: Bitboard, empty: Bitboard) -> Bitboard {
+    (pawns << 8) & empty
+}
+
+/// Returns the squares black pawns can reach by pushing one square forward.
+pub fn black_single_pushes(pawns: Bitboard, empty: Bitboard) -> Bitboard {
+    (pawns >> 8) & empty
+}
+
+/// Returns the squares white pawns can reach by pushing two squares forward
+/// from their starting rank.
+pub fn white_double_pushes(pawns: Bitboard, empty: Bitboard) -> Bitboard {
+    ((white_single_pushes(pawns, empty) & bitboard::RANK_3) << 8) & empty
+}
+
+/// Returns the squares black pawns can reach by pushing two squares forward
+/// from their starting rank.
+pub fn black_double_pushes(pawns: Bitboard, empty: Bitboard) -> Bitboard {
+    ((black_single_pushes(pawns, empty) & bitboard::RANK_6) >> 8) & empty
+}
+
+/// Returns the squares attacked by white pawns towards the h-file.
+pub fn white_east_attacks(pawns: Bitboard) -> Bitboard {
+    (pawns << 9) & !bitboard::FILE_A
+}
+
+/// Returns the squares attacked by white pawns towards the a-file.
+pub fn white_west_attacks(pawns: Bitboard) -> Bitboard {
+    (pawns << 7) & !bitboard::FILE_H
+}
+
+/// Returns the squares attacked by black pawns towards the h-file.
+pub fn black_east_attacks(pawns: Bitboard) -> Bitboard {
+    (pawns >> 7) & !bitboard::FILE_A
+}
+
+/// Returns the squares attacked by black pawns towards the a-file.
+pub fn black_west_attacks(pawns: Bitboard) -> Bitboard {
+    (pawns >> 9) & !bitboard::FILE_H
+}
+
+/// Returns the squares attacked by at least one white pawn.
+pub fn white_attacks(pawns: Bitboard) -> Bitboard {
+    white_east_attacks(pawns) | white_west_attacks(pawns)
+}
+
+/// Returns the squares attacked by at least one black pawn.
+pub fn black_attacks(pawns: Bitboard) -> Bitboard {
+    black_east_attacks(pawns) | black_west_attacks(pawns)
+}
+
+/// Returns the squares attacked by two white pawns.
+pub fn white_double_attacks(pawns: Bitboard) -> Bitboard {
+    white_east_attacks(pawns) & white_west_attacks(pawns)
+}
+
+/// Returns the squares attacked by two black pawns.
+pub fn black_double_attacks(pawns: Bitboard) -> Bitboard {
+    black_east_attacks(pawns) & black_west_attacks(pawns)
+}
+
+/// Returns the squares in front of white pawns, up to the last rank.
+pub fn white_front_span(pawns: Bitboard) -> Bitboard {
+    bitboard::north_fill(pawns) << 8
+}
+
+/// Returns the squares in front of black pawns, down to the first rank.
+pub fn black_front_span(pawns: Bitboard) -> Bitboard {
+    bitboard::south_fill(pawns) >> 8
+}
+
+/// Returns the squares behind white pawns, down to the first rank.
+pub fn white_rear_span(pawns: Bitboard) -> Bitboard {
+    bitboard::south_fill(pawns) >> 8
+}
+
+/// Returns the squares behind black pawns, up to the last rank.
+pub fn black_rear_span(pawns: Bitboard) -> Bitboard {
+    bitboard::north_fill(pawns) << 8
+}
+
+/// Returns every square white pawns may attack while advancing.
+pub fn white_attack_span(pawns: Bitboard) -> Bitboard {
+    white_attacks(bitboard::north_fill(pawns))
+}
+
+/// Returns every square black pawns may attack while advancing.
+pub fn black_attack_span(pawns: Bitboard) -> Bitboard {
+    black_attacks(bitboard::south_fill(pawns))
+}
+
+/// Returns the files next to the files of the given pawns.
+fn adjacent_files(pawns: Bitboard) -> Bitboard {
+    let files = bitboard::file_fill(pawns);
+    ((files << 1) & !bitboard::FILE_A) | ((files >> 1) & !bitboard::FILE_H)
+}
+
+/// Returns the white pawns that no black pawn can stop or capture on
+/// their way to promotion.
+pub fn white_passed_pawns(white: Bitboard, black: Bitboard) -> Bitboard {
+    white & !black_front_span(black) & !black_attack_span(black)
+}
+
+/// Returns the black pawns that no white pawn can stop or capture on
+/// their way to promotion.
+pub fn black_passed_pawns(white: Bitboard, black: Bitboard) -> Bitboard {
+    black & !white_front_span(white) & !white_attack_span(white)
+}
+
+/// Returns the pawns without friendly pawns on the adjacent files.
+pub fn isolated_pawns(pawns: Bitboard) -> Bitboard {
+    pawns & !adjacent_files(pawns)
+}
+
+/// Returns the white pawns with another white pawn in front of them on the
+/// same file.
+pub fn white_doubled_pawns(pawns: Bitboard) -> Bitboard {
+    pawns & white_rear_span(pawns)
+}
+
+/// Returns the black pawns with another black pawn in front of them on the
+/// same file.
+pub fn black_doubled_pawns(pawns: Bitboard) -> Bitboard {
+    pawns & black_rear_span(pawns)
+}
+
+/// Returns the white pawns whose stop square is attacked by a black pawn
+/// and can no longer be defended by a white pawn.
+pub fn white_backward_pawns(white: Bitboard, black: Bitboard) -> Bitboard {
+    ((white << 8) & black_attacks(black) & !white_attack_span(white)) >> 8
+}
+
+/// Returns the black pawns whose stop square is attacked by a white pawn
+/// and can no longer be defended by a black pawn.
+pub fn black_backward_pawns(white: Bitboard, black: Bitboard) -> Bitboard {
+    ((black >> 8) & white_attacks(white) & !black_attack_span(black)) << 8
+}
+
+/// Returns the pawns with a friendly pawn next to them on the same rank.
+pub fn phalanx_pawns(pawns: Bitboard) -> Bitboard {
+    pawns & (((pawns << 1) & !bitboard::FILE_A) | ((pawns >> 1) & !bitboard::FILE_H))
+}
+
+/// Returns the white pawns defended by another white pawn.
+pub fn white_supported_pawns(pawns: Bitboard) -> Bitboard {
+    pawns & white_attacks(pawns)
+}
+
+/// Returns the black pawns defended by another black pawn.
+pub fn black_supported_pawns(pawns: Bitboard) -> Bitboard {
+    pawns & black_attacks(pawns)
+}
+
+/// Returns the white pawns that are either supported or part of a phalanx.
+pub fn white_connected_pawns(pawns: Bitboard) -> Bitboard {
+    white_supported_pawns(pawns) | phalanx_pawns(pawns)
+}
+
+/// Returns the black pawns that are either supported or part of a phalanx.
+pub fn black_connected_pawns(pawns: Bitboard) -> Bitboard {
+    black_supported_pawns(pawns) | phalanx_pawns(pawns)
+}
+
+/// Returns the white pawns that are not passed yet, but have no black pawn
+/// in front of them and at least as many white pawns on the adjacent files
+/// to help them through as black pawns there to stop them.
+pub fn white_candidate_passers(white: Bitboard, black: Bitboard) -> Bitboard {
+    let mut candidates = bitboard::EMPTY;
+    let mut pawns = white & !black_front_span(black) & !white_passed_pawns(white, black);
+    while !bitboard::is_empty(pawns) {
+        let pawn = 1 << bitboard::bitscan_forward(pawns);
+        pawns &= pawns - 1;
+
+        let sentries = black & white_attack_span(pawn);
+        let helpers = white & adjacent_files(pawn) & !white_attack_span(pawn);
+        if bitboard::cardinality(helpers) >= bitboard::cardinality(sentries) {
+            candidates |= pawn;
+        }
+    }
+    candidates
+}
+
+/// Returns the black pawns that are not passed yet, but have no white pawn
+/// in front of them and at least as many black pawns on the adjacent files
+/// to help them through as white pawns there to stop them.
+pub fn black_candidate_passers(white: Bitboard, black: Bitboard) -> Bitboard {
+    let mut candidates = bitboard::EMPTY;
+    let mut pawns = black & !white_front_span(white) & !black_passed_pawns(white, black);
+    while !bitboard::is_empty(pawns) {
+        let pawn = 1 << bitboard::bitscan_forward(pawns);
+        pawns &= pawns - 1;
+
+        let sentries = white & black_attack_span(pawn);
+        let helpers = black & adjacent_files(pawn) & !black_attack_span(pawn);
+        if bitboard::cardinality(helpers) >= bitboard::cardinality(sentries) {
+            candidates |= pawn;
+        }
+    }
+    candidates
+}
+
+#[cfg(test)]
+mod test {
+    mod pawn {
+        pub use super::super::*;
+    }
+    use crate::bitboard;
+    use crate::bitboard::Bitboard;
+    use crate::bitboard::BitboardWrapper;
+    use crate::direction;
+    use crate::lookup;
+    use crate::square;
+    use crate::square::Square;
+
+    #[rustfmt::skip]
+    const WHITE_PAWNS: [Bitboard; 6] = [
+        0x000000000000ff00, // Starting position
+        0x0000000000000100, // Single pawn on A2
+        0x0000000000008000, // Single pawn on H2
+        0x000000081400e300, // Mixed structure
+        0x0000a50000420000, // Scattered pawns
+        0x00ffffffffffff00, // Every rank a pawn can stand on
+    ];
+
+    #[rustfmt::skip]
+    const BLACK_PAWNS: [Bitboard; 6] = [
+        0x00ff000000000000, // Starting position
+        0x0001000000000000, // Single pawn on A7
+        0x0080000000000000, // Single pawn on H7
+        0x00e7001800000000, // Mixed structure
+        0x0000420000a50000, // Scattered pawns
+        0x00ffffffffffff00, // Every rank a pawn can stand on
+    ];
+
+    #[rustfmt::skip]
+    const OCCUPANCIES: [Bitboard; 5] = [
+        bitboard::EMPTY,
+        0x0000000000ff0000, // Third rank is blocked
+        0x0000ff0000000000, // Sixth rank is blocked
+        0x00000000ff000000, // Fourth rank is blocked
+        0x0000240000420000, // A few scattered pieces
+    ];
+
+    fn squares(bitboard: Bitboard) -> impl Iterator<Item = u8> {
+        (0..64).filter(move |&square| bitboard & (1 << square) != 0)
+    }
+
+    fn bitboard(squares: &[Square]) -> Bitboard {
+        squares.iter().fold(bitboard::EMPTY, |bitboard, &square| {
+            bitboard | (1 << square)
+        })
+    }
+
+    #[test]
+    fn white_pushes() {
+        for (i, &pawns) in WHITE_PAWNS.iter().enumerate() {
+            for (j, &occ) in OCCUPANCIES.iter().enumerate() {
+                let occ = occ & !pawns;
+                let empty = !(occ | pawns);
+
+                let mut pushes = bitboard::EMPTY;
+                for square in squares(pawns) {
+                    pushes |= lookup::WHITE_PAWN_PUSH_TABLE.lookup(square, occ | pawns);
+                }
+                let pushes = pushes;
+
+                assert_eq!(
+                    BitboardWrapper(pushes),
+                    BitboardWrapper(
+                        pawn::white_single_pushes(pawns, empty)
+                            | pawn::white_double_pushes(pawns, empty)
+                    ),
+                    "Test case #{}/{} failed",
+                    i,
+                    j
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn black_pushes() {
+        for (i, &pawns) in BLACK_PAWNS.iter().enumerate() {
+            for (j, &occ) in OCCUPANCIES.iter().enumerate() {
+                let occ = occ & !pawns;
+                let empty = !(occ | pawns);
+
+                let mut pushes = bitboard::EMPTY;
+                for square in squares(pawns) {
+                    pushes |= lookup::BLACK_PAWN_PUSH_TABLE.lookup(square, occ | pawns);
+                }
+                let pushes = pushes;
+
+                assert_eq!(
+                    BitboardWrapper(pushes),
+                    BitboardWrapper(
+                        pawn::black_single_pushes(pawns, empty)
+                            | pawn::black_double_pushes(pawns, empty)
+                    ),
+                    "Test case #{}/{} failed",
+                    i,
+                    j
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn single_and_double_pushes_do_not_overlap() {
+        for &occ in OCCUPANCIES.iter() {
+            for &pawns in WHITE_PAWNS.iter() {
+                let empty = !(occ | pawns);
+                assert_eq!(
+                    bitboard::EMPTY,
+                    pawn::white_single_pushes(pawns, empty)
+                        & pawn::white_double_pushes(pawns, empty)
+                );
+            }
+            for &pawns in BLACK_PAWNS.iter() {
+                let empty = !(occ | pawns);
+                assert_eq!(
+                    bitboard::EMPTY,
+                    pawn::black_single_pushes(pawns, empty)
+                        & pawn::black_double_pushes(pawns, empty)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn white_attacks() {
+        for (i, &pawns) in WHITE_PAWNS.iter().enumerate() {
+            let mut attacks = bitboard::EMPTY;
+            let mut double_attacks = bitboard::EMPTY;
+            for square in squares(pawns) {
+                let lookup = lookup::WHITE_PAWN_ATTACK_TABLE.lookup(square);
+                double_attacks |= attacks & lookup;
+                attacks |= lookup;
+            }
+            let attacks = attacks;
+            let double_attacks = double_attacks;
+
+            assert_eq!(
+                BitboardWrapper(attacks),
+                BitboardWrapper(pawn::white_attacks(pawns)),
+                "Test case #{} failed",
+                i
+            );
+            assert_eq!(
+                BitboardWrapper(double_attacks),
+                BitboardWrapper(pawn::white_double_attacks(pawns)),
+                "Test case #{} failed",
+                i
+            );
+        }
+    }
+
+    #[test]
+    fn black_attacks() {
+        for (i, &pawns) in BLACK_PAWNS.iter().enumerate() {
+            let mut attacks = bitboard::EMPTY;
+            let mut double_attacks = bitboard::EMPTY;
+            for square in squares(pawns) {
+                let lookup = lookup::BLACK_PAWN_ATTACK_TABLE.lookup(square);
+                double_attacks |= attacks & lookup;
+                attacks |= lookup;
+            }
+            let attacks = attacks;
+            let double_attacks = double_attacks;
+
+            assert_eq!(
+                BitboardWrapper(attacks),
+                BitboardWrapper(pawn::black_attacks(pawns)),
+                "Test case #{} failed",
+                i
+            );
+            assert_eq!(
+                BitboardWrapper(double_attacks),
+                BitboardWrapper(pawn::black_double_attacks(pawns)),
+                "Test case #{} failed",
+                i
+            );
+        }
+    }
+
+    #[test]
+    fn white_spans() {
+        for (i, &pawns) in WHITE_PAWNS.iter().enumerate() {
+            let mut front_span = bitboard::EMPTY;
+            let mut rear_span = bitboard::EMPTY;
+            let mut attack_span = bitboard::EMPTY;
+            for square in squares(pawns) {
+                let front = lookup::ray(square, direction::NORTH);
+                front_span |= front;
+                rear_span |= lookup::ray(square, direction::SOUTH);
+                for square in squares(front | (1 << square)) {
+                    attack_span |= lookup::WHITE_PAWN_ATTACK_TABLE.lookup(square);
+                }
+            }
+            let front_span = front_span;
+            let rear_span = rear_span;
+            let attack_span = attack_span;
+
+            assert_eq!(
+                BitboardWrapper(front_span),
+                BitboardWrapper(pawn::white_front_span(pawns)),
+                "Test case #{} failed",
+                i
+            );
+            assert_eq!(
+                BitboardWrapper(rear_span),
+                BitboardWrapper(pawn::white_rear_span(pawns)),
+                "Test case #{} failed",
+                i
+            );
+            assert_eq!(
+                BitboardWrapper(attack_span),
+                BitboardWrapper(pawn::white_attack_span(pawns)),
+                "Test case #{} failed",
+                i
+            );
+        }
+    }
+
+    #[test]
+    fn black_spans() {
+        for (i, &pawns) in BLACK_PAWNS.iter().enumerate() {
+            let mut front_span = bitboard::EMPTY;
+            let mut rear_span = bitboard::EMPTY;
+            let mut attack_span = bitboard::EMPTY;
+            for square in squares(pawns) {
+                let front = lookup::ray(square, direction::SOUTH);
+                front_span |= front;
+                rear_span |= lookup::ray(square, direction::NORTH);
+                for square in squares(front | (1 << square)) {
+                    attack_span |= lookup::BLACK_PAWN_ATTACK_TABLE.lookup(square);
+                }
+            }
+            let front_span = front_span;
+            let rear_span = rear_span;
+            let attack_span = attack_span;
+
+            assert_eq!(
+                BitboardWrapper(front_span),
+                BitboardWrapper(pawn::black_front_span(pawns)),
+                "Test case #{} failed",
+                i
+            );
+            assert_eq!(
+                BitboardWrapper(rear_span),
+                BitboardWrapper(pawn::black_rear_span(pawns)),
+                "Test case #{} failed",
+                i
+            );
+            assert_eq!(
+                BitboardWrapper(attack_span),
+                BitboardWrapper(pawn::black_attack_span(pawns)),
+                "Test case #{} failed",
+                i
+            );
+        }
+    }
+
+    #[test]
+    #[rustfmt::skip]
+    fn white_passed_pawns() {
+        for (i, &(white, black, expected)) in [
+            (&vec![square::D5], &vec![square::E6], &vec![]),
+            (&vec![square::D5], &vec![square::E5], &vec![square::D5]),
+            (&vec![square::D5], &vec![square::D7], &vec![]),
+            (&vec![square::A4, square::H5, square::C2], &vec![square::A6, square::G7], &vec![square::C2]),
+            (&vec![square::B2, square::C2], &vec![], &vec![square::B2, square::C2]),
+        ]
+        .iter()
+        .enumerate()
+        {
+            assert_eq!(
+                BitboardWrapper(bitboard(expected)),
+                BitboardWrapper(pawn::white_passed_pawns(bitboard(white), bitboard(black))),
+                "Test case #{} failed",
+                i
+            );
+        }
+    }
+
+    #[test]
+    #[rustfmt::skip]
+    fn black_passed_pawns() {
+        for (i, &(white, black, expected)) in [
+            (&vec![square::D3], &vec![square::E4], &vec![]),
+            (&vec![square::D4], &vec![square::E4], &vec![square::E4]),
+            (&vec![square::E2], &vec![square::E4], &vec![]),
+            (&vec![square::A3, square::G7], &vec![square::A5, square::H4, square::C7], &vec![square::H4, square::C7]),
+        ]
+        .iter()
+        .enumerate()
+        {
+            assert_eq!(
+                BitboardWrapper(bitboard(expected)),
+                BitboardWrapper(pawn::black_passed_pawns(bitboard(white), bitboard(black))),
+                "Test case #{} failed",
+                i
+            );
+        }
+    }
+
+    #[test]
+    #[rustfmt::skip]
+    fn isolated_pawns() {
+        for (i, &(pawns, expected)) in [
+            (&vec![square::A2, square::C3, square::D4, square::F2, square::H2, square::H3], &vec![square::A2, square::F2, square::H2, square::H3]),
+            (&vec![square::A2, square::B2, square::G2, square::H2], &vec![]),
+            (&vec![square::H2, square::A3], &vec![square::H2, square::A3]),
+        ]
+        .iter()
+        .enumerate()
+        {
+            assert_eq!(
+                BitboardWrapper(bitboard(expected)),
+                BitboardWrapper(pawn::isolated_pawns(bitboard(pawns))),
+                "Test case #{} failed",
+                i
+            );
+        }
+    }
+
+    #[test]
+    #[rustfmt::skip]
+    fn white_doubled_pawns() {
+        for (i, &(pawns, expected)) in [
+            (&vec![square::C2, square::C4, square::C5, square::E3], &vec![square::C2, square::C4]),
+            (&vec![square::A2, square::B2], &vec![]),
+        ]
+        .iter()
+        .enumerate()
+        {
+            assert_eq!(
+                BitboardWrapper(bitboard(expected)),
+                BitboardWrapper(pawn::white_doubled_pawns(bitboard(pawns))),
+                "Test case #{} failed",
+                i
+            );
+        }
+    }
+
+    #[test]
+    #[rustfmt::skip]
+    fn black_doubled_pawns() {
+        for (i, &(pawns, expected)) in [
+            (&vec![square::C2, square::C4, square::C5, square::E3], &vec![square::C4, square::C5]),
+            (&vec![square::A7, square::B7], &vec![]),
+        ]
+        .iter()
+        .enumerate()
+        {
+            assert_eq!(
+                BitboardWrapper(bitboard(expected)),
+                BitboardWrapper(pawn::black_doubled_pawns(bitboard(pawns))),
+                "Test case #{} failed",
+                i
+            );
+        }
+    }
+
+    #[test]
+    #[rustfmt::skip]
+    fn white_backward_pawns() {
+        for (i, &(white, black, expected)) in [
+            (&vec![square::D3, square::E4], &vec![square::E5], &vec![square::D3]),
+            (&vec![square::C2, square::D3, square::E4], &vec![square::E5], &vec![]),
+            (&vec![square::D3], &vec![square::C6], &vec![]),
+        ]
+        .iter()
+        .enumerate()
+        {
+            assert_eq!(
+                BitboardWrapper(bitboard(expected)),
+                BitboardWrapper(pawn::white_backward_pawns(bitboard(white), bitboard(black))),
+                "Test case #{} failed",
+                i
+            );
+        }
+    }
+
+    #[test]
+    #[rustfmt::skip]
+    fn black_backward_pawns() {
+        for (i, &(white, black, expected)) in [
+            (&vec![square::E4], &vec![square::D6, square::E5], &vec![square::D6]),
+            (&vec![square::E4], &vec![square::C7, square::D6, square::E5], &vec![]),
+            (&vec![square::C3], &vec![square::D6], &vec![]),
+        ]
+        .iter()
+        .enumerate()
+        {
+            assert_eq!(
+                BitboardWrapper(bitboard(expected)),
+                BitboardWrapper(pawn::black_backward_pawns(bitboard(white), bitboard(black))),
+                "Test case #{} failed",
+                i
+            );
+        }
+    }
+
+    #[test]
+    #[rustfmt::skip]
+    fn phalanx_pawns() {
+        for (i, &(pawns, expected)) in [
+            (&vec![square::D4, square::E4, square::H4, square::A5], &vec![square::D4, square::E4]),
+            (&vec![square::A2, square::B2, square::C2, square::E3], &vec![square::A2, square::B2, square::C2]),
+        ]
+        .iter()
+        .enumerate()
+        {
+            assert_eq!(
+                BitboardWrapper(bitboard(expected)),
+                BitboardWrapper(pawn::phalanx_pawns(bitboard(pawns))),
+                "Test case #{} failed",
+                i
+            );
+        }
+    }
+
+    #[test]
+    #[rustfmt::skip]
+    fn white_supported_pawns() {
+        for (i, &(pawns, expected)) in [
+            (&vec![square::D4, square::E3, square::H3, square::A4], &vec![square::D4]),
+            (&vec![square::B3, square::C4, square::D5], &vec![square::C4, square::D5]),
+        ]
+        .iter()
+        .enumerate()
+        {
+            assert_eq!(
+                BitboardWrapper(bitboard(expected)),
+                BitboardWrapper(pawn::white_supported_pawns(bitboard(pawns))),
+                "Test case #{} failed",
+                i
+            );
+        }
+    }
+
+    #[test]
+    #[rustfmt::skip]
+    fn black_supported_pawns() {
+        for (i, &(pawns, expected)) in [
+            (&vec![square::D4, square::E3, square::H3, square::A4], &vec![square::E3]),
+            (&vec![square::B6, square::C5, square::D4], &vec![square::C5, square::D4]),
+        ]
+        .iter()
+        .enumerate()
+        {
+            assert_eq!(
+                BitboardWrapper(bitboard(expected)),
+                BitboardWrapper(pawn::black_supported_pawns(bitboard(pawns))),
+                "Test case #{} failed",
+                i
+            );
+        }
+    }
+
+    #[test]
+    #[rustfmt::skip]
+    fn white_connected_pawns() {
+        for (i, &(pawns, expected)) in [
+            (&vec![square::D4, square::E3, square::F3, square::A2], &vec![square::D4, square::E3, square::F3]),
+            (&vec![square::A2, square::C2, square::E2], &vec![]),
+        ]
+        .iter()
+        .enumerate()
+        {
+            assert_eq!(
+                BitboardWrapper(bitboard(expected)),
+                BitboardWrapper(pawn::white_connected_pawns(bitboard(pawns))),
+                "Test case #{} failed",
+                i
+            );
+        }
+    }
+
+    #[test]
+    #[rustfmt::skip]
+    fn black_connected_pawns() {
+        for (i, &(pawns, expected)) in [
+            (&vec![square::D4, square::E5, square::F5, square::A7], &vec![square::D4, square::E5, square::F5]),
+            (&vec![square::A7, square::C7, square::E7], &vec![]),
+        ]
+        .iter()
+        .enumerate()
+        {
+            assert_eq!(
+                BitboardWrapper(bitboard(expected)),
+                BitboardWrapper(pawn::black_connected_pawns(bitboard(pawns))),
+                "Test case #{} failed",
+                i
+            );
+        }
+    }
+
+    #[test]
+    #[rustfmt::skip]
+    fn white_candidate_passers() {
+        for (i, &(white, black, expected)) in [
+            (&vec![square::B2, square::C4], &vec![square::D6], &vec![square::C4]),
+            (&vec![square::C4], &vec![square::B6, square::D6], &vec![]),
+            (&vec![square::C4], &vec![square::C6], &vec![]),
+            (&vec![square::B3, square::C4, square::D3], &vec![square::B6, square::D6], &vec![square::C4]),
+        ]
+        .iter()
+        .enumerate()
+        {
+            assert_eq!(
+                BitboardWrapper(bitboard(expected)),
+                BitboardWrapper(pawn::white_candidate_passers(bitboard(white), bitboard(black))),
+                "Test case #{} failed",
+                i
+            );
+        }
+    }
+
+    #[test]
+    #[rustfmt::skip]
+    fn black_candidate_passers() {
+        for (i, &(white, black, expected)) in [
+            (&vec![square::D3], &vec![square::B7, square::C5], &vec![square::C5]),
+            (&vec![square::B3, square::D3], &vec![square::C5], &vec![]),
+            (&vec![square::C3], &vec![square::C5], &vec![]),
+        ]
+        .iter()
+        .enumerate()
+        {
+            assert_eq!(
+                BitboardWrapper(bitboard(expected)),
+                BitboardWrapper(pawn::black_candidate_passers(bitboard(white), bitboard(black))),
+                "Test case #{} failed",
+                i
+            );
+        }
+    }
+}
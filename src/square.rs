@@ -1,3 +1,5 @@
+use crate::colour;
+use crate::colour::Colour;
 use crate::file::File;
 use crate::file::FileWrapper;
 use crate::rank::Rank;
@@ -105,6 +107,27 @@ pub fn rank(square: Square) -> Rank {
     square / 8
 }
 
+/// Returns the square colour: white for light squares, black for dark ones.
+pub fn colour(square: Square) -> Colour {
+    if (file(square) + rank(square)).is_multiple_of(2) {
+        colour::BLACK
+    } else {
+        colour::WHITE
+    }
+}
+
+/// Mirrors the square horizontally, so that file a becomes file h and vice versa.
+pub fn mirror(square: Square) -> Square {
+    square ^ 7
+}
+
+/// Flips the square vertically, so that rank 1 becomes rank 8 and vice versa.
+///
+/// Useful to look up white-relative tables from black's point of view.
+pub fn flip(square: Square) -> Square {
+    square ^ 56
+}
+
 impl std::fmt::Debug for SquareWrapper {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
@@ -121,6 +144,8 @@ mod test {
     mod square {
         pub use super::super::*;
     }
+    use crate::colour;
+    use crate::colour::ColourWrapper;
     use crate::file;
 
     #[test]
@@ -344,4 +369,244 @@ mod test {
             assert_eq!(rank, square::rank(square), "Test case #{} failed", i);
         }
     }
+
+    #[test]
+    fn colour() {
+        for (i, &(square, colour)) in [
+            (square::A1, colour::BLACK),
+            (square::A2, colour::WHITE),
+            (square::A3, colour::BLACK),
+            (square::A4, colour::WHITE),
+            (square::A5, colour::BLACK),
+            (square::A6, colour::WHITE),
+            (square::A7, colour::BLACK),
+            (square::A8, colour::WHITE),
+            (square::B1, colour::WHITE),
+            (square::B2, colour::BLACK),
+            (square::B3, colour::WHITE),
+            (square::B4, colour::BLACK),
+            (square::B5, colour::WHITE),
+            (square::B6, colour::BLACK),
+            (square::B7, colour::WHITE),
+            (square::B8, colour::BLACK),
+            (square::C1, colour::BLACK),
+            (square::C2, colour::WHITE),
+            (square::C3, colour::BLACK),
+            (square::C4, colour::WHITE),
+            (square::C5, colour::BLACK),
+            (square::C6, colour::WHITE),
+            (square::C7, colour::BLACK),
+            (square::C8, colour::WHITE),
+            (square::D1, colour::WHITE),
+            (square::D2, colour::BLACK),
+            (square::D3, colour::WHITE),
+            (square::D4, colour::BLACK),
+            (square::D5, colour::WHITE),
+            (square::D6, colour::BLACK),
+            (square::D7, colour::WHITE),
+            (square::D8, colour::BLACK),
+            (square::E1, colour::BLACK),
+            (square::E2, colour::WHITE),
+            (square::E3, colour::BLACK),
+            (square::E4, colour::WHITE),
+            (square::E5, colour::BLACK),
+            (square::E6, colour::WHITE),
+            (square::E7, colour::BLACK),
+            (square::E8, colour::WHITE),
+            (square::F1, colour::WHITE),
+            (square::F2, colour::BLACK),
+            (square::F3, colour::WHITE),
+            (square::F4, colour::BLACK),
+            (square::F5, colour::WHITE),
+            (square::F6, colour::BLACK),
+            (square::F7, colour::WHITE),
+            (square::F8, colour::BLACK),
+            (square::G1, colour::BLACK),
+            (square::G2, colour::WHITE),
+            (square::G3, colour::BLACK),
+            (square::G4, colour::WHITE),
+            (square::G5, colour::BLACK),
+            (square::G6, colour::WHITE),
+            (square::G7, colour::BLACK),
+            (square::G8, colour::WHITE),
+            (square::H1, colour::WHITE),
+            (square::H2, colour::BLACK),
+            (square::H3, colour::WHITE),
+            (square::H4, colour::BLACK),
+            (square::H5, colour::WHITE),
+            (square::H6, colour::BLACK),
+            (square::H7, colour::WHITE),
+            (square::H8, colour::BLACK),
+        ]
+        .iter()
+        .enumerate()
+        {
+            assert_eq!(
+                ColourWrapper(colour),
+                ColourWrapper(square::colour(square)),
+                "Test case #{} failed",
+                i
+            );
+        }
+    }
+
+    #[test]
+    fn flip() {
+        for (i, &(square, flipped)) in [
+            (square::A1, square::A8),
+            (square::A2, square::A7),
+            (square::A3, square::A6),
+            (square::A4, square::A5),
+            (square::A5, square::A4),
+            (square::A6, square::A3),
+            (square::A7, square::A2),
+            (square::A8, square::A1),
+            (square::B1, square::B8),
+            (square::B2, square::B7),
+            (square::B3, square::B6),
+            (square::B4, square::B5),
+            (square::B5, square::B4),
+            (square::B6, square::B3),
+            (square::B7, square::B2),
+            (square::B8, square::B1),
+            (square::C1, square::C8),
+            (square::C2, square::C7),
+            (square::C3, square::C6),
+            (square::C4, square::C5),
+            (square::C5, square::C4),
+            (square::C6, square::C3),
+            (square::C7, square::C2),
+            (square::C8, square::C1),
+            (square::D1, square::D8),
+            (square::D2, square::D7),
+            (square::D3, square::D6),
+            (square::D4, square::D5),
+            (square::D5, square::D4),
+            (square::D6, square::D3),
+            (square::D7, square::D2),
+            (square::D8, square::D1),
+            (square::E1, square::E8),
+            (square::E2, square::E7),
+            (square::E3, square::E6),
+            (square::E4, square::E5),
+            (square::E5, square::E4),
+            (square::E6, square::E3),
+            (square::E7, square::E2),
+            (square::E8, square::E1),
+            (square::F1, square::F8),
+            (square::F2, square::F7),
+            (square::F3, square::F6),
+            (square::F4, square::F5),
+            (square::F5, square::F4),
+            (square::F6, square::F3),
+            (square::F7, square::F2),
+            (square::F8, square::F1),
+            (square::G1, square::G8),
+            (square::G2, square::G7),
+            (square::G3, square::G6),
+            (square::G4, square::G5),
+            (square::G5, square::G4),
+            (square::G6, square::G3),
+            (square::G7, square::G2),
+            (square::G8, square::G1),
+            (square::H1, square::H8),
+            (square::H2, square::H7),
+            (square::H3, square::H6),
+            (square::H4, square::H5),
+            (square::H5, square::H4),
+            (square::H6, square::H3),
+            (square::H7, square::H2),
+            (square::H8, square::H1),
+        ]
+        .iter()
+        .enumerate()
+        {
+            assert_eq!(
+                square::SquareWrapper(flipped),
+                square::SquareWrapper(square::flip(square)),
+                "Test case #{} failed",
+                i
+            );
+        }
+    }
+
+    #[test]
+    fn mirror() {
+        for (i, &(square, mirrored)) in [
+            (square::A1, square::H1),
+            (square::A2, square::H2),
+            (square::A3, square::H3),
+            (square::A4, square::H4),
+            (square::A5, square::H5),
+            (square::A6, square::H6),
+            (square::A7, square::H7),
+            (square::A8, square::H8),
+            (square::B1, square::G1),
+            (square::B2, square::G2),
+            (square::B3, square::G3),
+            (square::B4, square::G4),
+            (square::B5, square::G5),
+            (square::B6, square::G6),
+            (square::B7, square::G7),
+            (square::B8, square::G8),
+            (square::C1, square::F1),
+            (square::C2, square::F2),
+            (square::C3, square::F3),
+            (square::C4, square::F4),
+            (square::C5, square::F5),
+            (square::C6, square::F6),
+            (square::C7, square::F7),
+            (square::C8, square::F8),
+            (square::D1, square::E1),
+            (square::D2, square::E2),
+            (square::D3, square::E3),
+            (square::D4, square::E4),
+            (square::D5, square::E5),
+            (square::D6, square::E6),
+            (square::D7, square::E7),
+            (square::D8, square::E8),
+            (square::E1, square::D1),
+            (square::E2, square::D2),
+            (square::E3, square::D3),
+            (square::E4, square::D4),
+            (square::E5, square::D5),
+            (square::E6, square::D6),
+            (square::E7, square::D7),
+            (square::E8, square::D8),
+            (square::F1, square::C1),
+            (square::F2, square::C2),
+            (square::F3, square::C3),
+            (square::F4, square::C4),
+            (square::F5, square::C5),
+            (square::F6, square::C6),
+            (square::F7, square::C7),
+            (square::F8, square::C8),
+            (square::G1, square::B1),
+            (square::G2, square::B2),
+            (square::G3, square::B3),
+            (square::G4, square::B4),
+            (square::G5, square::B5),
+            (square::G6, square::B6),
+            (square::G7, square::B7),
+            (square::G8, square::B8),
+            (square::H1, square::A1),
+            (square::H2, square::A2),
+            (square::H3, square::A3),
+            (square::H4, square::A4),
+            (square::H5, square::A5),
+            (square::H6, square::A6),
+            (square::H7, square::A7),
+            (square::H8, square::A8),
+        ]
+        .iter()
+        .enumerate()
+        {
+            assert_eq!(
+                square::SquareWrapper(mirrored),
+                square::SquareWrapper(square::mirror(square)),
+                "Test case #{} failed",
+                i
+            );
+        }
+    }
 }
@@ -0,0 +1,321 @@
+use crate::colour;
+use crate::colour::Colour;
+
+use std::time::Duration;
+
+/// Number of moves a sudden death time control is expected to last for.
+const DEFAULT_MOVES_TO_GO: u32 = 30;
+
+/// Upper bound on the number of moves the remaining time is spread over.
+const MAX_MOVES_TO_GO: u32 = 50;
+
+/// Soft limit scaling (in percent), indexed by the number of consecutive
+/// iterations the best move has stayed the same.
+const STABILITY_SCALE: [u32; 5] = [150, 120, 100, 85, 70];
+
+/// Clock state for a single move, as sent by `go` in UCI.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Clock {
+    pub wtime: Option<Duration>,
+    pub btime: Option<Duration>,
+    pub winc: Duration,
+    pub binc: Duration,
+    pub movestogo: Option<u32>,
+    pub movetime: Option<Duration>,
+}
+
+/// Decides how long to think on a move.
+///
+/// The soft limit is checked between iterations to decide whether to start
+/// another one, while the hard limit aborts the search at any point.
+#[derive(Debug)]
+pub struct TimeManager {
+    soft: Duration,
+    hard: Duration,
+    stability: usize,
+    score: Option<i32>,
+    scale: u32,
+}
+
+impl TimeManager {
+    /// Creates a time manager for the given side to move.
+    ///
+    /// Returns `None` if the clock does not limit the search (e.g. infinite
+    /// or depth-limited analysis). The move overhead is subtracted from the
+    /// remaining time to account for communication latency.
+    pub fn new(clock: &Clock, side: Colour, move_overhead: Duration) -> Option<TimeManager> {
+        if let Some(movetime) = clock.movetime {
+            let limit = movetime.saturating_sub(move_overhead);
+            return Some(TimeManager::with_limits(limit, limit));
+        }
+
+        let (time, increment) = match side {
+            colour::WHITE => (clock.wtime?, clock.winc),
+            colour::BLACK => (clock.btime?, clock.binc),
+            _ => unreachable!(),
+        };
+        let available = time.saturating_sub(move_overhead);
+        let moves_to_go = clock
+            .movestogo
+            .unwrap_or(DEFAULT_MOVES_TO_GO)
+            .clamp(1, MAX_MOVES_TO_GO);
+
+        // Never plan to use more than 80% of what is left on the clock.
+        let max = available * 4 / 5;
+        let hard = (available / moves_to_go * 5 + increment).min(max);
+        let soft = (available / moves_to_go + increment * 3 / 4).min(hard);
+
+        Some(TimeManager::with_limits(soft, hard))
+    }
+
+    fn with_limits(soft: Duration, hard: Duration) -> TimeManager {
+        TimeManager {
+            soft,
+            hard,
+            stability: 0,
+            score: None,
+            scale: 100,
+        }
+    }
+
+    /// Records the outcome of a completed iteration.
+    ///
+    /// The soft limit shrinks as the best move stays the same across
+    /// iterations and grows when the score drops from the previous one.
+    pub fn update(&mut self, best_move_changed: bool, score: i32) {
+        self.stability = if best_move_changed || self.score.is_none() {
+            0
+        } else {
+            (self.stability + 1).min(STABILITY_SCALE.len() - 1)
+        };
+
+        let drop = self
+            .score
+            .map_or(0, |previous| (previous - score).clamp(0, 200));
+        self.score = Some(score);
+
+        self.scale = STABILITY_SCALE[self.stability] * (100 + drop as u32 / 2) / 100;
+    }
+
+    /// Returns the time after which no new iteration should be started.
+    pub fn soft_limit(&self) -> Duration {
+        (self.soft * self.scale / 100).min(self.hard)
+    }
+
+    /// Returns the time after which the search must stop.
+    pub fn hard_limit(&self) -> Duration {
+        self.hard
+    }
+}
+
+#[cfg(test)]
+mod test {
+    mod timeman {
+        pub use super::super::*;
+    }
+    use crate::colour;
+
+    use std::time::Duration;
+
+    const OVERHEAD: Duration = Duration::from_millis(50);
+
+    fn ms(millis: u64) -> Duration {
+        Duration::from_millis(millis)
+    }
+
+    #[test]
+    fn limits() {
+        for (i, &(clock, side, soft, hard)) in [
+            // Fixed time per move
+            (
+                timeman::Clock {
+                    movetime: Some(ms(1000)),
+                    ..Default::default()
+                },
+                colour::WHITE,
+                ms(950),
+                ms(950),
+            ),
+            // Fixed time per move takes precedence over the clock
+            (
+                timeman::Clock {
+                    wtime: Some(ms(60_000)),
+                    movetime: Some(ms(2000)),
+                    ..Default::default()
+                },
+                colour::WHITE,
+                ms(1950),
+                ms(1950),
+            ),
+            // Sudden death
+            (
+                timeman::Clock {
+                    wtime: Some(ms(60_050)),
+                    btime: Some(ms(1000)),
+                    ..Default::default()
+                },
+                colour::WHITE,
+                ms(2000),
+                ms(10_000),
+            ),
+            // Sudden death, reading black's clock
+            (
+                timeman::Clock {
+                    wtime: Some(ms(1000)),
+                    btime: Some(ms(60_050)),
+                    ..Default::default()
+                },
+                colour::BLACK,
+                ms(2000),
+                ms(10_000),
+            ),
+            // Increment
+            (
+                timeman::Clock {
+                    wtime: Some(ms(30_050)),
+                    btime: Some(ms(30_050)),
+                    winc: ms(1000),
+                    binc: ms(2000),
+                    ..Default::default()
+                },
+                colour::BLACK,
+                ms(2500),
+                ms(7000),
+            ),
+            // Increment larger than what is left on the clock
+            (
+                timeman::Clock {
+                    wtime: Some(ms(1050)),
+                    winc: ms(5000),
+                    ..Default::default()
+                },
+                colour::WHITE,
+                ms(800),
+                ms(800),
+            ),
+            // Moves to go
+            (
+                timeman::Clock {
+                    wtime: Some(ms(100_050)),
+                    movestogo: Some(10),
+                    ..Default::default()
+                },
+                colour::WHITE,
+                ms(10_000),
+                ms(50_000),
+            ),
+            // Last move before the time control
+            (
+                timeman::Clock {
+                    wtime: Some(ms(10_050)),
+                    movestogo: Some(1),
+                    ..Default::default()
+                },
+                colour::WHITE,
+                ms(8000),
+                ms(8000),
+            ),
+            // Less time left than the move overhead
+            (
+                timeman::Clock {
+                    wtime: Some(ms(20)),
+                    winc: ms(100),
+                    ..Default::default()
+                },
+                colour::WHITE,
+                ms(0),
+                ms(0),
+            ),
+        ]
+        .iter()
+        .enumerate()
+        {
+            let manager = timeman::TimeManager::new(&clock, side, OVERHEAD).unwrap();
+            assert_eq!(soft, manager.soft_limit(), "Test case #{} failed", i);
+            assert_eq!(hard, manager.hard_limit(), "Test case #{} failed", i);
+        }
+    }
+
+    #[test]
+    fn unlimited() {
+        for (i, &(clock, side)) in [
+            (timeman::Clock::default(), colour::WHITE),
+            (timeman::Clock::default(), colour::BLACK),
+            // Only the opponent's clock is known
+            (
+                timeman::Clock {
+                    btime: Some(ms(60_000)),
+                    ..Default::default()
+                },
+                colour::WHITE,
+            ),
+        ]
+        .iter()
+        .enumerate()
+        {
+            assert!(
+                timeman::TimeManager::new(&clock, side, OVERHEAD).is_none(),
+                "Test case #{} failed",
+                i
+            );
+        }
+    }
+
+    #[test]
+    fn stable_best_move_shrinks_soft_limit() {
+        let clock = timeman::Clock {
+            wtime: Some(ms(60_050)),
+            ..Default::default()
+        };
+        let mut manager = timeman::TimeManager::new(&clock, colour::WHITE, OVERHEAD).unwrap();
+
+        // The first iteration has nothing to compare against.
+        manager.update(true, 20);
+        assert_eq!(ms(3000), manager.soft_limit());
+
+        for &soft in [ms(2400), ms(2000), ms(1700), ms(1400), ms(1400)].iter() {
+            manager.update(false, 20);
+            assert_eq!(soft, manager.soft_limit());
+        }
+
+        // A new best move resets the stability.
+        manager.update(true, 20);
+        assert_eq!(ms(3000), manager.soft_limit());
+    }
+
+    #[test]
+    fn score_drop_grows_soft_limit() {
+        let clock = timeman::Clock {
+            wtime: Some(ms(60_050)),
+            ..Default::default()
+        };
+        let mut manager = timeman::TimeManager::new(&clock, colour::WHITE, OVERHEAD).unwrap();
+
+        manager.update(true, 50);
+        manager.update(false, 50);
+        manager.update(false, 50);
+        assert_eq!(ms(2000), manager.soft_limit());
+
+        // Losing 60 centipawns gives 30% more time on top of the stability scaling.
+        manager.update(false, -10);
+        assert_eq!(ms(2200), manager.soft_limit());
+
+        // Score increases do not.
+        manager.update(false, 90);
+        assert_eq!(ms(1400), manager.soft_limit());
+    }
+
+    #[test]
+    fn soft_limit_never_exceeds_hard_limit() {
+        let clock = timeman::Clock {
+            wtime: Some(ms(2050)),
+            winc: ms(1000),
+            ..Default::default()
+        };
+        let mut manager = timeman::TimeManager::new(&clock, colour::WHITE, OVERHEAD).unwrap();
+
+        manager.update(true, 300);
+        manager.update(true, -300);
+        assert_eq!(manager.hard_limit(), manager.soft_limit());
+    }
+}